@@ -18,6 +18,12 @@ use std::str;
 use std::str::FromStr;
 use std::cmp::Ordering;
 
+#[cfg(feature = "likely-subtags")]
+mod likely_subtags;
+
+#[cfg(feature = "likely-subtags")]
+pub use likely_subtags::expand_likely_subtags;
+
 #[derive(Debug)]
 struct Language {
     name: String,
@@ -51,33 +57,100 @@ impl PartialEq for Language {
 }
 
 impl Language {
-    fn new(tag: &str) -> Language {
+    /// Splits a single `name;q=...` entry into its name and the raw `q=...` parameter, if any.
+    fn split_tag(tag: &str) -> (&str, Option<&str>) {
         let mut tag_parts = tag.split(";");
-        let name = match tag_parts.nth(0) {
-            Some(name_str) => name_str.to_string(),
-            None => String::from("")
-        };
-        let quality = match tag_parts.nth(0) {
-            Some(quality_str) => Language::quality_with_default(quality_str),
+        let name = tag_parts.nth(0).unwrap_or("");
+        let raw_quality = tag_parts.nth(0);
+
+        (name, raw_quality)
+    }
+
+    fn new(tag: &str) -> Language {
+        let (name, raw_quality) = Language::split_tag(tag);
+        let quality = raw_quality.map_or(1.0, Language::quality_with_default);
+
+        Language { name: name.to_string(), quality }
+    }
+
+    fn try_new(tag: &str) -> Result<Language, QualityError> {
+        let (name, raw_quality) = Language::split_tag(tag);
+        let quality = match raw_quality {
+            Some(raw_quality) => Language::try_quality(raw_quality)?,
             None => 1.0
         };
 
-        Language {
-            name: name,
-            quality: quality
-        }
+        Ok(Language { name: name.to_string(), quality })
     }
 
+    /// Falls back to a quality of `0.0` for anything that doesn't parse, rather than propagating
+    /// an error, so `parse`/`intersection` keep a tag with a malformed weight (ranked last, as
+    /// `q=0.0`) instead of failing.
     fn quality_with_default(raw_quality: &str) -> f64 {
-        let quality_str = &raw_quality[2..];
+        Language::try_quality(raw_quality).unwrap_or(0.0)
+    }
+
+    /// Parses a `q=` parameter per the RFC 7231 qvalue grammar: `q=` (case-insensitive) followed
+    /// by `0[.0-3 digits]` or `1[.0-3 "0"s]`. Anything else, including an out-of-range value like
+    /// `q=5.0` or input too short to hold a prefix, is a [`QualityError`].
+    fn try_quality(raw_quality: &str) -> Result<f64, QualityError> {
+        let invalid = || QualityError { raw: raw_quality.to_string() };
 
-        match f64::from_str(&quality_str) {
-            Ok(q) => q,
-            Err(_) => 0.0
+        let prefix = raw_quality.get(..2).ok_or_else(invalid)?;
+        if !prefix.eq_ignore_ascii_case("q=") {
+            return Err(invalid());
         }
+
+        let value = &raw_quality[2..];
+        if !is_valid_qvalue(value) {
+            return Err(invalid());
+        }
+
+        f64::from_str(value).map_err(|_| invalid())
+    }
+}
+
+/// Returns `true` if `value` is a valid RFC 7231 qvalue body (the part after `q=`): `0`
+/// optionally followed by `.` and up to three digits, or `1` optionally followed by `.` and up
+/// to three `0`s.
+fn is_valid_qvalue(value: &str) -> bool {
+    let mut chars = value.chars();
+
+    let leading = match chars.next() {
+        Some(c @ '0') | Some(c @ '1') => c,
+        _ => return false
+    };
+
+    match chars.next() {
+        None => return true,
+        Some('.') => {}
+        _ => return false
+    }
+
+    let fraction: Vec<char> = chars.collect();
+
+    if fraction.len() > 3 || !fraction.iter().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    leading == '0' || fraction.iter().all(|&c| c == '0')
+}
+
+/// Error returned by [`try_parse`] when a raw Accept-Language quality value (`q=...`) does not
+/// conform to the RFC 7231 qvalue grammar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QualityError {
+    raw: String
+}
+
+impl std::fmt::Display for QualityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid Accept-Language quality value {:?}, expected e.g. \"q=0.8\"", self.raw)
     }
 }
 
+impl std::error::Error for QualityError {}
+
 /// Parse a raw Accept-Language header value into an ordered list of language tags.
 /// This should return the exact same list as `window.navigator.languages` in supported browsers.
 ///
@@ -89,6 +162,44 @@ impl Language {
 /// let user_languages = parse("en-US, en-GB;q=0.5");
 /// ```
 pub fn parse(raw_languages: &str) -> Vec<String> {
+    parse_languages(raw_languages)
+        .iter()
+        .map(|ref l| l.name.to_owned())
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// Like [`parse`], but rejects the whole header with a [`QualityError`] if any tag's `q=`
+/// parameter is malformed, instead of silently treating it as `q=0.0`. Use this when you'd rather
+/// reject a clearly broken `Accept-Language` header than negotiate against a guess.
+///
+/// # Example
+///
+/// ```
+/// use accept_language::try_parse;
+///
+/// let user_languages = try_parse("en-US, en-GB;q=0.5").unwrap();
+/// assert_eq!(user_languages, vec![String::from("en-US"), String::from("en-GB")]);
+///
+/// assert!(try_parse("en-US;q=5.0").is_err());
+/// ```
+pub fn try_parse(raw_languages: &str) -> Result<Vec<String>, QualityError> {
+    let languages_string = raw_languages.replace(" ", "");
+    let mut languages: Vec<Language> = languages_string
+        .split(",")
+        .map(Language::try_new)
+        .collect::<Result<_, _>>()?;
+
+    languages.sort();
+
+    Ok(languages
+        .iter()
+        .map(|l| l.name.to_owned())
+        .filter(|l| !l.is_empty())
+        .collect())
+}
+
+fn parse_languages(raw_languages: &str) -> Vec<Language> {
     let languages_string = raw_languages.clone().replace(" ", "");
     let languages_str_parts: Vec<&str> = languages_string.split(",").collect();
     let mut languages_string_parts: Vec<Language> = languages_str_parts
@@ -99,10 +210,64 @@ pub fn parse(raw_languages: &str) -> Vec<String> {
     languages_string_parts.sort();
 
     languages_string_parts
-        .iter()
-        .map(|ref l| l.name.to_owned())
-        .filter(|l| !l.is_empty())
-        .collect()
+}
+
+/// Parse a raw Accept-Language header value into an iterator of `(tag, quality)` pairs borrowed
+/// from `raw_languages`, ordered from highest to lowest quality, without allocating an owned
+/// `String` per tag. Use this (or [`best_match`]) in throughput-sensitive code that negotiates a
+/// language on every request; [`parse`] remains the convenient owned-output entry point.
+///
+/// # Example
+///
+/// ```
+/// use accept_language::parse_iter;
+///
+/// let user_languages: Vec<(&str, f32)> = parse_iter("en-US, en-GB;q=0.5").collect();
+///
+/// assert_eq!(user_languages, vec![("en-US", 1.0), ("en-GB", 0.5)]);
+/// ```
+pub fn parse_iter(raw_languages: &str) -> impl Iterator<Item = (&str, f32)> {
+    let mut tags: Vec<(&str, f32)> = raw_languages
+        .split(",")
+        .filter_map(|tag| {
+            let mut tag_parts = tag.trim().splitn(2, ";");
+            let name = tag_parts.next()?.trim();
+
+            if name.is_empty() {
+                return None;
+            }
+
+            let quality = match tag_parts.next() {
+                Some(raw_quality) => Language::try_quality(raw_quality.trim()).unwrap_or(0.0) as f32,
+                None => 1.0
+            };
+
+            Some((name, quality))
+        })
+        .collect();
+
+    tags.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+    tags.into_iter()
+}
+
+/// Returns the single highest-quality tag in `raw_languages` that's present in
+/// `supported_languages`, without allocating an owned `String` per tag (only the winning match
+/// is converted to an owned `String`).
+///
+/// # Example
+///
+/// ```
+/// use accept_language::best_match;
+///
+/// let language = best_match("en-US, en-GB;q=0.5", vec!["de", "en-GB"]);
+///
+/// assert_eq!(language, Some(String::from("en-GB")));
+/// ```
+pub fn best_match(raw_languages: &str, supported_languages: Vec<&str>) -> Option<String> {
+    parse_iter(raw_languages)
+        .find(|(name, _)| supported_languages.contains(name))
+        .map(|(name, _)| name.to_string())
 }
 
 /// Compare an Accept-Language header value with your application's supported languages to find
@@ -126,9 +291,249 @@ pub fn intersection(raw_languages: &str, supported_languages: Vec<&str>) -> Vec<
     intersection
 }
 
+/// Like [`intersection`], but pairs each matched supported language with the quality value
+/// (`q`) the user expressed for it, so callers can distinguish a primary preference (`q=1.0`)
+/// from a weak fallback.
+///
+/// # Example
+///
+/// ```
+/// use accept_language::intersection_with_quality;
+///
+/// let common_languages = intersection_with_quality("en-US, en-GB;q=0.5", vec!["en-US", "de", "en-GB"]);
+///
+/// assert_eq!(common_languages, vec![(String::from("en-US"), 1.0), (String::from("en-GB"), 0.5)]);
+/// ```
+pub fn intersection_with_quality(raw_languages: &str, supported_languages: Vec<&str>) -> Vec<(String, f32)> {
+    parse_languages(raw_languages)
+        .into_iter()
+        .filter(|l| !l.name.is_empty() && supported_languages.contains(&l.name.as_str()))
+        .map(|l| (l.name, l.quality as f32))
+        .collect()
+}
+
+/// Like [`intersection`], but assumes `supported_languages` is already sorted (ascending) and
+/// uses a binary search instead of a linear scan, turning the per-call cost from
+/// O(users × supported) into O(users × log supported). Passing unsorted input silently produces
+/// wrong results; if you can't guarantee sorted input, use [`intersection`] instead.
+///
+/// # Example
+///
+/// ```
+/// use accept_language::intersection_ordered;
+///
+/// // note: supported_languages must already be sorted
+/// let common_languages = intersection_ordered("en-US, en-GB;q=0.5", vec!["de", "en-GB", "en-US"]);
+/// ```
+pub fn intersection_ordered(raw_languages: &str, supported_languages: Vec<&str>) -> Vec<String> {
+    parse(raw_languages)
+        .into_iter()
+        .filter(|l| supported_languages.binary_search(&l.as_str()).is_ok())
+        .collect()
+}
+
+/// Like [`intersection_with_quality`], but assumes `supported_languages` is already sorted
+/// (ascending) and uses a binary search instead of a linear scan; see [`intersection_ordered`]
+/// for the sorted-input precondition.
+///
+/// # Example
+///
+/// ```
+/// use accept_language::intersection_ordered_with_quality;
+///
+/// // note: supported_languages must already be sorted
+/// let common_languages = intersection_ordered_with_quality("en-US, en-GB;q=0.5", vec!["de", "en-GB", "en-US"]);
+/// ```
+pub fn intersection_ordered_with_quality(raw_languages: &str, supported_languages: Vec<&str>) -> Vec<(String, f32)> {
+    parse_languages(raw_languages)
+        .into_iter()
+        .filter(|l| !l.name.is_empty() && supported_languages.binary_search(&l.name.as_str()).is_ok())
+        .map(|l| (l.name, l.quality as f32))
+        .collect()
+}
+
+/// Like [`intersection`], but matches supported language ranges against requested tags using
+/// RFC 4647 basic filtering instead of requiring an exact match.
+///
+/// A supported range matches a requested tag if the range equals the tag or the tag begins with
+/// the range followed by `-` (so a supported `en` matches a requested `en-US` or `en-Latn-GB`,
+/// but not `eng`). Matching is case-insensitive, and a requested `*` range matches any supported
+/// language. Results are ordered by the requested q-value, highest first.
+///
+/// # Example
+///
+/// ```
+/// use accept_language::intersection_filtered;
+///
+/// let common_languages = intersection_filtered("en-US, en-GB;q=0.5", vec!["en", "de"]);
+///
+/// assert_eq!(common_languages, vec![String::from("en-US"), String::from("en-GB")]);
+/// ```
+pub fn intersection_filtered(raw_languages: &str, supported_languages: Vec<&str>) -> Vec<String> {
+    let mut matched: Vec<String> = Vec::new();
+
+    for language in parse_languages(raw_languages).into_iter().filter(|l| !l.name.is_empty()) {
+        for range in &supported_languages {
+            if basic_filtering_matches(range, &language.name) {
+                let result = if language.name == "*" { range.to_string() } else { language.name.clone() };
+
+                if !matched.contains(&result) {
+                    matched.push(result);
+                }
+            }
+        }
+    }
+
+    matched
+}
+
+/// Returns `true` if `range` matches `tag` per the RFC 4647 basic filtering algorithm.
+fn basic_filtering_matches(range: &str, tag: &str) -> bool {
+    if tag == "*" {
+        return true;
+    }
+
+    if range.eq_ignore_ascii_case(tag) {
+        return true;
+    }
+
+    tag.len() > range.len()
+        && tag.as_bytes()[range.len()] == b'-'
+        && tag[..range.len()].eq_ignore_ascii_case(range)
+}
+
+/// Strategy controlling how many and which available locales [`negotiate`] selects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NegotiationStrategy {
+    /// Return every available locale that matches any requested locale (a requested `en` pulls
+    /// in `en-US`, `en-GB`, etc.).
+    Filtering,
+    /// Return at most one available locale per requested locale, preserving requested order.
+    Matching,
+    /// Return exactly one best available locale (RFC 4647 lookup), falling back to `default`
+    /// when nothing matches; with no match and no `default`, nothing is returned.
+    Lookup,
+}
+
+/// Negotiate which of an application's `available` locales to serve, given the user's
+/// `requested` locales (most preferred first, such as the output of [`parse`]).
+///
+/// The three [`NegotiationStrategy`] variants trade off how many locales come back and how
+/// strictly they must match; see their docs for details. An `available` locale is only ever
+/// returned once, even if it would otherwise satisfy more than one requested locale.
+///
+/// # Example
+///
+/// ```
+/// use accept_language::{negotiate, NegotiationStrategy};
+///
+/// let locales = negotiate(&["en-US-posix"], &["en-US", "de"], Some("en"), NegotiationStrategy::Lookup);
+///
+/// assert_eq!(locales, vec![String::from("en-US")]);
+/// ```
+pub fn negotiate(
+    requested: &[&str],
+    available: &[&str],
+    default: Option<&str>,
+    strategy: NegotiationStrategy,
+) -> Vec<String> {
+    let mut remaining: Vec<&str> = available.to_vec();
+    let mut matched = Vec::new();
+
+    for tag in requested {
+        match strategy {
+            NegotiationStrategy::Filtering => {
+                let mut i = 0;
+                while i < remaining.len() {
+                    if tags_match(tag, remaining[i]) {
+                        matched.push(remaining.remove(i).to_string());
+                    } else {
+                        i += 1;
+                    }
+                }
+            }
+            NegotiationStrategy::Matching => {
+                if let Some(pos) = remaining.iter().position(|available_tag| tags_match(tag, available_tag)) {
+                    matched.push(remaining.remove(pos).to_string());
+                }
+            }
+            NegotiationStrategy::Lookup => {
+                for form in truncations(tag) {
+                    if let Some(pos) = remaining.iter().position(|available_tag| available_tag.eq_ignore_ascii_case(&form)) {
+                        matched.push(remaining.remove(pos).to_string());
+                        return matched;
+                    }
+                }
+            }
+        }
+    }
+
+    if matched.is_empty() && strategy == NegotiationStrategy::Lookup {
+        if let Some(default) = default {
+            return vec![default.to_string()];
+        }
+    }
+
+    matched
+}
+
+/// Returns `true` if `a` and `b` are equal, or either is a prefix of the other up to a `-`
+/// boundary (case-insensitive), so e.g. `en` and `en-US` match in either order.
+fn tag_prefix_matches(a: &str, b: &str) -> bool {
+    if a.eq_ignore_ascii_case(b) {
+        return true;
+    }
+
+    if b.len() > a.len() && b.as_bytes()[a.len()] == b'-' && b[..a.len()].eq_ignore_ascii_case(a) {
+        return true;
+    }
+
+    a.len() > b.len() && a.as_bytes()[b.len()] == b'-' && a[..b.len()].eq_ignore_ascii_case(b)
+}
+
+/// Returns `true` if `a` and `b` should be treated as a negotiation match: either is a prefix of
+/// the other (see [`tag_prefix_matches`]), or — with the `likely-subtags` feature enabled — they
+/// expand to the same maximal form, so e.g. `zh-TW` and `zh-Hant` can match even though neither is
+/// a literal prefix of the other.
+fn tags_match(a: &str, b: &str) -> bool {
+    if tag_prefix_matches(a, b) {
+        return true;
+    }
+
+    #[cfg(feature = "likely-subtags")]
+    {
+        if likely_subtags::expand_likely_subtags(a).eq_ignore_ascii_case(&likely_subtags::expand_likely_subtags(b)) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Returns `tag` along with each progressively less specific form produced by dropping its
+/// trailing `-`-separated subtag, e.g. `en-US-posix` -> `["en-US-posix", "en-US", "en"]`.
+fn truncations(tag: &str) -> Vec<String> {
+    let mut forms = Vec::new();
+    let mut current = tag.to_string();
+
+    loop {
+        forms.push(current.clone());
+
+        match current.rfind('-') {
+            Some(idx) => current.truncate(idx),
+            None => break,
+        }
+    }
+
+    forms
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{intersection, Language, parse};
+    use super::{
+        best_match, intersection, intersection_filtered, intersection_ordered, intersection_ordered_with_quality,
+        intersection_with_quality, negotiate, parse, parse_iter, try_parse, Language, NegotiationStrategy,
+    };
 
     static MOCK_ACCEPT_LANGUAGE: &str = "en-US, de;q=0.7, jp;q=0.1";
 
@@ -160,6 +565,51 @@ mod tests {
         assert_eq!(quality, 0.0)
     }
 
+    #[test]
+    fn it_rejects_an_out_of_range_quality() {
+        let quality = Language::quality_with_default("q=5.0");
+
+        assert_eq!(quality, 0.0)
+    }
+
+    #[test]
+    fn it_rejects_a_quality_shorter_than_the_q_prefix() {
+        let quality = Language::quality_with_default("q");
+
+        assert_eq!(quality, 0.0)
+    }
+
+    #[test]
+    fn it_rejects_a_multibyte_quality_without_panicking_on_the_byte_slice() {
+        let quality = Language::quality_with_default("\u{20AC}");
+
+        assert_eq!(quality, 0.0)
+    }
+
+    #[test]
+    fn it_accepts_a_case_insensitive_q_prefix() {
+        let quality = Language::quality_with_default("Q=0.5");
+
+        assert_eq!(quality, 0.5)
+    }
+
+    #[test]
+    fn it_try_parses_a_valid_accept_language_header() {
+        let user_languages = try_parse("en-US, en-GB;q=0.5").unwrap();
+
+        assert_eq!(user_languages, vec![String::from("en-US"), String::from("en-GB")])
+    }
+
+    #[test]
+    fn it_try_parses_an_out_of_range_quality_as_an_error() {
+        assert!(try_parse("en-US;q=5.0").is_err())
+    }
+
+    #[test]
+    fn it_try_parses_a_malformed_quality_as_an_error() {
+        assert!(try_parse("en-US;q=yolo").is_err())
+    }
+
     #[test]
     fn it_parses_a_valid_accept_language_header() {
         let user_languages = parse(MOCK_ACCEPT_LANGUAGE);
@@ -181,6 +631,34 @@ mod tests {
         assert_eq!(user_languages, vec![String::from("en-US"), String::from("jp"), String::from("de")])
     }
 
+    #[test]
+    fn it_parses_an_accept_language_header_into_borrowed_tags_with_quality() {
+        let user_languages: Vec<(&str, f32)> = parse_iter("en-US, en-GB;q=0.5").collect();
+
+        assert_eq!(user_languages, vec![("en-US", 1.0), ("en-GB", 0.5)])
+    }
+
+    #[test]
+    fn it_parses_an_empty_accept_language_header_with_parse_iter() {
+        let user_languages: Vec<(&str, f32)> = parse_iter("").collect();
+
+        assert_eq!(user_languages.len(), 0)
+    }
+
+    #[test]
+    fn it_finds_the_best_match_without_materializing_every_tag() {
+        let language = best_match(MOCK_ACCEPT_LANGUAGE, vec!["de", "en-GB"]);
+
+        assert_eq!(language, Some(String::from("de")))
+    }
+
+    #[test]
+    fn it_finds_no_best_match_when_nothing_is_supported() {
+        let language = best_match(MOCK_ACCEPT_LANGUAGE, vec!["fr", "en-GB"]);
+
+        assert_eq!(language, None)
+    }
+
     #[test]
     fn it_returns_language_intersections() {
         let common_languages = intersection(MOCK_ACCEPT_LANGUAGE, vec!["en-US", "jp"]);
@@ -194,4 +672,131 @@ mod tests {
 
         assert_eq!(common_languages.len(), 0)
     }
+
+    #[test]
+    fn it_returns_language_intersections_with_quality() {
+        let common_languages = intersection_with_quality("en-US, en-GB;q=0.5", vec!["en-US", "de", "en-GB"]);
+
+        assert_eq!(common_languages, vec![(String::from("en-US"), 1.0), (String::from("en-GB"), 0.5)])
+    }
+
+    #[test]
+    fn it_returns_an_empty_array_when_no_intersections_with_quality() {
+        let common_languages = intersection_with_quality(MOCK_ACCEPT_LANGUAGE, vec!["fr", "en-GB"]);
+
+        assert_eq!(common_languages.len(), 0)
+    }
+
+    #[test]
+    fn it_returns_language_intersections_using_binary_search() {
+        let common_languages = intersection_ordered(MOCK_ACCEPT_LANGUAGE, vec!["de", "en-US", "jp"]);
+
+        assert_eq!(common_languages, vec![String::from("en-US"), String::from("de"), String::from("jp")])
+    }
+
+    #[test]
+    fn it_returns_an_empty_array_when_no_intersections_using_binary_search() {
+        let common_languages = intersection_ordered(MOCK_ACCEPT_LANGUAGE, vec!["en-GB", "fr"]);
+
+        assert_eq!(common_languages.len(), 0)
+    }
+
+    #[test]
+    fn it_returns_language_intersections_with_quality_using_binary_search() {
+        let common_languages = intersection_ordered_with_quality("en-US, en-GB;q=0.5", vec!["de", "en-GB", "en-US"]);
+
+        assert_eq!(common_languages, vec![(String::from("en-US"), 1.0), (String::from("en-GB"), 0.5)])
+    }
+
+    #[test]
+    fn it_matches_a_range_against_a_more_specific_requested_tag() {
+        let common_languages = intersection_filtered("en-US, en-GB;q=0.5", vec!["en", "de"]);
+
+        assert_eq!(common_languages, vec![String::from("en-US"), String::from("en-GB")])
+    }
+
+    #[test]
+    fn it_does_not_match_a_range_against_an_unrelated_prefix() {
+        let common_languages = intersection_filtered("eng;q=1.0", vec!["en"]);
+
+        assert_eq!(common_languages.len(), 0)
+    }
+
+    #[test]
+    fn it_matches_a_requested_wildcard_against_any_supported_language() {
+        let common_languages = intersection_filtered("*;q=0.8", vec!["en", "de"]);
+
+        assert_eq!(common_languages, vec![String::from("en"), String::from("de")])
+    }
+
+    #[test]
+    fn it_matches_ranges_case_insensitively() {
+        let common_languages = intersection_filtered("EN-us", vec!["en"]);
+
+        assert_eq!(common_languages, vec![String::from("EN-us")])
+    }
+
+    #[test]
+    fn it_does_not_duplicate_a_tag_matched_by_more_than_one_range() {
+        let common_languages = intersection_filtered("en-GB", vec!["en", "en-GB"]);
+
+        assert_eq!(common_languages, vec![String::from("en-GB")])
+    }
+
+    #[test]
+    fn it_does_not_duplicate_a_tag_matched_by_both_a_wildcard_and_an_explicit_range() {
+        let common_languages = intersection_filtered("de, *;q=0.1", vec!["en", "de"]);
+
+        assert_eq!(common_languages, vec![String::from("de"), String::from("en")])
+    }
+
+    #[test]
+    fn it_does_not_duplicate_a_tag_across_multiple_requested_entries() {
+        let common_languages = intersection_filtered("en-US,en", vec!["en", "en-US"]);
+
+        assert_eq!(common_languages, vec![String::from("en-US"), String::from("en")])
+    }
+
+    #[test]
+    fn it_negotiates_filtering_pulling_in_every_matching_available_locale() {
+        let locales = negotiate(&["en"], &["en-GB", "en-US", "de"], None, NegotiationStrategy::Filtering);
+
+        assert_eq!(locales, vec![String::from("en-GB"), String::from("en-US")])
+    }
+
+    #[test]
+    fn it_negotiates_matching_at_most_one_locale_per_requested_locale() {
+        let locales = negotiate(&["en", "de"], &["en-GB", "en-US", "de"], None, NegotiationStrategy::Matching);
+
+        assert_eq!(locales, vec![String::from("en-GB"), String::from("de")])
+    }
+
+    #[test]
+    fn it_negotiates_lookup_falling_back_to_truncated_forms() {
+        let locales = negotiate(&["en-US-posix"], &["en", "de"], None, NegotiationStrategy::Lookup);
+
+        assert_eq!(locales, vec![String::from("en")])
+    }
+
+    #[test]
+    fn it_negotiates_lookup_falling_back_to_the_default() {
+        let locales = negotiate(&["fr"], &["en", "de"], Some("en"), NegotiationStrategy::Lookup);
+
+        assert_eq!(locales, vec![String::from("en")])
+    }
+
+    #[test]
+    fn it_negotiates_lookup_returning_nothing_without_a_default() {
+        let locales = negotiate(&["fr"], &["en", "de"], None, NegotiationStrategy::Lookup);
+
+        assert_eq!(locales.len(), 0)
+    }
+
+    #[test]
+    #[cfg(feature = "likely-subtags")]
+    fn it_negotiates_filtering_matching_tags_that_share_a_maximal_form() {
+        let locales = negotiate(&["zh-CN"], &["zh-Hans-CN", "de"], None, NegotiationStrategy::Filtering);
+
+        assert_eq!(locales, vec![String::from("zh-Hans-CN")])
+    }
 }
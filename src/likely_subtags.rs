@@ -0,0 +1,127 @@
+//! Likely-subtags expansion, used to fill a language tag out to its maximal
+//! `language-Script-Region` form so that e.g. `zh-TW` can be compared against `zh-Hant`, or
+//! `zh-CN` against `zh-Hans-CN`, even when neither is a literal prefix of the other.
+//!
+//! The table below is a small representative subset of CLDR's `likelySubtags.xml`, large enough
+//! to exercise the algorithm; swap in the full CLDR table if your application needs broader
+//! language coverage. It's gated behind the `likely-subtags` feature so crates that don't need it
+//! aren't forced to carry the extra data.
+
+/// `(tag, maximized_tag)` pairs used as progressively less specific lookup keys.
+const LIKELY_SUBTAGS: &[(&str, &str)] = &[
+    ("en", "en-Latn-US"),
+    ("en-GB", "en-Latn-GB"),
+    ("de", "de-Latn-DE"),
+    ("fr", "fr-Latn-FR"),
+    ("es", "es-Latn-ES"),
+    ("pt", "pt-Latn-BR"),
+    ("ru", "ru-Cyrl-RU"),
+    ("ja", "ja-Jpan-JP"),
+    ("ko", "ko-Kore-KR"),
+    ("ar", "ar-Arab-EG"),
+    ("zh", "zh-Hans-CN"),
+    ("zh-TW", "zh-Hant-TW"),
+    ("zh-Hant", "zh-Hant-TW"),
+    ("sr", "sr-Cyrl-RS"),
+    ("sr-Latn", "sr-Latn-RS"),
+];
+
+/// Expands `tag` to its maximal `language-Script-Region` form using the bundled likely-subtags
+/// table.
+///
+/// The algorithm looks up `tag` itself, then progressively less specific forms of it
+/// (`language-Script-Region` -> `language-Region` -> `language-Script` -> `language`) until one
+/// is found in the table, then merges the found script/region into `tag` wherever `tag` left
+/// them unspecified. Subtags already present on `tag` are never overwritten.
+///
+/// # Example
+///
+/// ```
+/// use accept_language::expand_likely_subtags;
+///
+/// assert_eq!(expand_likely_subtags("zh"), "zh-Hans-CN");
+/// assert_eq!(expand_likely_subtags("zh-TW"), "zh-Hant-TW");
+/// assert_eq!(expand_likely_subtags("en-GB"), "en-Latn-GB");
+/// ```
+pub fn expand_likely_subtags(tag: &str) -> String {
+    let parts: Vec<&str> = tag.split('-').collect();
+    let language = parts[0];
+    let script = parts.iter().skip(1).find(|part| is_script_subtag(part)).copied();
+    let region = parts.iter().skip(1).find(|part| !is_script_subtag(part)).copied();
+
+    let lookup_keys: [Option<String>; 4] = [
+        match (script, region) {
+            (Some(script), Some(region)) => Some(format!("{}-{}-{}", language, script, region)),
+            _ => None,
+        },
+        region.map(|region| format!("{}-{}", language, region)),
+        script.map(|script| format!("{}-{}", language, script)),
+        Some(language.to_string()),
+    ];
+
+    let maximized = lookup_keys.iter().flatten().find_map(|key| find_maximized_tag(key));
+
+    match maximized {
+        Some(maximized) => merge(language, script, region, maximized),
+        None => tag.to_string(),
+    }
+}
+
+fn find_maximized_tag(key: &str) -> Option<&'static str> {
+    LIKELY_SUBTAGS
+        .iter()
+        .find(|(tag, _)| tag.eq_ignore_ascii_case(key))
+        .map(|(_, maximized)| *maximized)
+}
+
+/// Merges a maximized tag's script/region into the original tag's language, filling in only the
+/// subtags the original left unspecified.
+fn merge(language: &str, script: Option<&str>, region: Option<&str>, maximized: &str) -> String {
+    let max_parts: Vec<&str> = maximized.split('-').collect();
+
+    if max_parts.len() < 3 {
+        return maximized.to_string();
+    }
+
+    format!("{}-{}-{}", language, script.unwrap_or(max_parts[1]), region.unwrap_or(max_parts[2]))
+}
+
+/// A script subtag is a 4-letter, title-cased code (e.g. `Hant`), distinguishing it from a
+/// 2-letter or 3-digit region subtag (e.g. `TW`, `419`).
+fn is_script_subtag(subtag: &str) -> bool {
+    let mut chars = subtag.chars();
+
+    subtag.len() == 4
+        && chars.next().map_or(false, |c| c.is_ascii_uppercase())
+        && chars.all(|c| c.is_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand_likely_subtags;
+
+    #[test]
+    fn it_expands_a_bare_language_to_its_maximal_form() {
+        assert_eq!(expand_likely_subtags("en"), "en-Latn-US")
+    }
+
+    #[test]
+    fn it_expands_a_language_and_region_preserving_the_region() {
+        assert_eq!(expand_likely_subtags("en-GB"), "en-Latn-GB")
+    }
+
+    #[test]
+    fn it_expands_a_language_and_region_unknown_to_the_table_using_the_language_default() {
+        assert_eq!(expand_likely_subtags("de-CH"), "de-Latn-CH")
+    }
+
+    #[test]
+    fn it_expands_a_language_and_script_preserving_the_script() {
+        assert_eq!(expand_likely_subtags("zh-Hant"), "zh-Hant-TW")
+    }
+
+    #[test]
+    fn it_leaves_an_unrecognized_language_unchanged() {
+        assert_eq!(expand_likely_subtags("xx"), "xx")
+    }
+}